@@ -1,4 +1,53 @@
 use std::cmp::Ordering;
+use std::error;
+use std::fmt;
+
+/// An error produced while parsing a token stream into a `Node`.
+///
+/// Each variant other than `EmptyInput` carries the index of the offending
+/// token within the sub-slice being parsed when the problem was detected,
+/// not within the whole token stream: a parenthetical's index is relative to
+/// its own contents, and an operand's index is relative to the tokens that
+/// follow the binary or unary operator before it (so `a + b c` reports
+/// `MissingOperator { index: 1 }`).
+#[derive(Debug, Eq, PartialEq)]
+enum ParseError {
+    EmptyInput,
+    UnmatchedOpenParen { index: usize },
+    UnmatchedCloseParen { index: usize },
+    LeadingOperator { index: usize },
+    MissingOperator { index: usize },
+    TrailingOperator { index: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::EmptyInput => write!(f, "empty token stream"),
+            Self::UnmatchedOpenParen { index } => {
+                write!(f, "unmatched open brace at token {index}")
+            }
+            Self::UnmatchedCloseParen { index } => {
+                write!(f, "unmatched close brace at token {index}")
+            }
+            Self::LeadingOperator { index } => write!(
+                f,
+                "operator found at beginning of token stream (token {index}); only `-` and `+` can be unary operators"
+            ),
+            Self::MissingOperator { index } => {
+                write!(
+                    f,
+                    "operator expected before token {index}; implicit multiplication is not enabled"
+                )
+            }
+            Self::TrailingOperator { index } => {
+                write!(f, "operator at token {index} has no right-hand operand")
+            }
+        }
+    }
+}
+
+impl error::Error for ParseError {}
 
 #[derive(Debug, Eq, PartialEq)]
 enum Operator {
@@ -7,17 +56,43 @@ enum Operator {
     Mul,
     Div,
     Exp,
+    Eq,
+    NotEq,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    And,
+    Or,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Associativity {
+    Left,
+    Right,
 }
 
 impl Operator {
+    // Lowest to highest: logical, relational, additive, multiplicative,
+    // exponential -- matching the uutils `expr` precedence tiers.
     fn precedence(&self) -> i64 {
         match self {
+            Self::Or => 1,
+            Self::And => 2,
+            Self::Eq | Self::NotEq | Self::Lt | Self::Le | Self::Gt | Self::Ge => 5,
             Self::Add | Self::Sub => 10,
             Self::Mul | Self::Div => 100,
             Self::Exp => 1000,
         }
     }
 
+    fn associativity(&self) -> Associativity {
+        match self {
+            Self::Exp => Associativity::Right,
+            _ => Associativity::Left,
+        }
+    }
+
     fn maybe(s: &str) -> Option<Operator> {
         match s {
             "+" => Some(Self::Add),
@@ -25,6 +100,14 @@ impl Operator {
             "*" => Some(Self::Mul),
             "/" => Some(Self::Div),
             "^" => Some(Self::Exp),
+            "=" => Some(Self::Eq),
+            "!=" => Some(Self::NotEq),
+            "<" => Some(Self::Lt),
+            "<=" => Some(Self::Le),
+            ">" => Some(Self::Gt),
+            ">=" => Some(Self::Ge),
+            "&" => Some(Self::And),
+            "|" => Some(Self::Or),
             _ => None,
         }
     }
@@ -34,6 +117,31 @@ impl Operator {
     }
 }
 
+/// A unary prefix operator: negation (`-a`) or unary plus (`+a`).
+///
+/// Unary operators bind tighter than every binary operator, including `^`,
+/// with one conventional exception: when a unary operator sits to the left
+/// of `^`, the exponentiation binds first and the unary negates the whole
+/// power (`- a ^ b` is `-(a ^ b)`, matching most calculators), whereas a
+/// unary operator to the right of `^` only negates the exponent (`a ^ - b`
+/// is `a ^ (-b)`). `parse_unary_operand` implements this by greedily folding
+/// in a following `^` chain before wrapping the result in `Node::Unary`.
+#[derive(Debug, Eq, PartialEq)]
+enum UnaryOperator {
+    Neg,
+    Pos,
+}
+
+impl UnaryOperator {
+    fn maybe(s: &str) -> Option<UnaryOperator> {
+        match s {
+            "-" => Some(Self::Neg),
+            "+" => Some(Self::Pos),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq)]
 enum Node {
     Operation {
@@ -41,39 +149,134 @@ enum Node {
         left: Box<Node>,
         right: Box<Node>,
     },
+    Unary {
+        op: UnaryOperator,
+        operand: Box<Node>,
+    },
     Parenthetical(Box<Node>),
     Leaf(String),
 }
 
-fn parse(toks: &[String]) -> Node {
+/// Options controlling how ambiguous token sequences are parsed.
+#[derive(Debug, Clone, Copy, Default)]
+struct ParseOptions {
+    /// When two operands are juxtaposed with no operator between them (e.g.
+    /// `2 ( x + 1 )`), treat it as multiplication instead of rejecting it
+    /// with `ParseError::MissingOperator`.
+    implicit_mul: bool,
+}
+
+fn parse(toks: &[String]) -> Result<Node, ParseError> {
+    parse_with_options(toks, &ParseOptions::default())
+}
+
+fn parse_with_options(toks: &[String], options: &ParseOptions) -> Result<Node, ParseError> {
+    let (left, rest) = parse_primary(toks, options)?;
+
+    match rest.split_first() {
+        None => Ok(left),
+        Some((tok, tok_rest)) => {
+            let tok_index = toks.len() - rest.len();
+            match Operator::maybe(tok) {
+                Some(_) if tok_rest.is_empty() => {
+                    Err(ParseError::TrailingOperator { index: tok_index })
+                }
+                Some(op) => Ok(compose_with_precedence(
+                    op,
+                    left,
+                    parse_with_options(tok_rest, options)?,
+                )),
+                None if options.implicit_mul => Ok(compose_with_precedence(
+                    Operator::Mul,
+                    left,
+                    parse_with_options(rest, options)?,
+                )),
+                None => Err(ParseError::MissingOperator { index: tok_index }),
+            }
+        }
+    }
+}
+
+// Parses a single primary expression: a leaf, a parenthetical, or a unary
+// prefix operator applied to one. Returns the parsed node plus whatever
+// tokens remain.
+fn parse_primary<'a>(
+    toks: &'a [String],
+    options: &ParseOptions,
+) -> Result<(Node, &'a [String]), ParseError> {
     if toks.is_empty() {
-        panic!("empty token stream");
+        return Err(ParseError::EmptyInput);
     }
 
     let (first, rest) = toks.split_first().unwrap();
+
+    if let Some(op) = UnaryOperator::maybe(first) {
+        return parse_unary(op, rest, options);
+    }
     if Operator::maybe(first).is_some() {
-        panic!("operator found at beginning of token stream. unary operators not supported.")
+        return Err(ParseError::LeadingOperator { index: 0 });
     }
 
-    let (left, rest) = match first.as_str() {
-        ")" => panic!("unmatched close brace"),
-        "(" => parse_parenthetical(rest),
-        _ => (Node::Leaf(first.to_string()), rest),
-    };
+    match first.as_str() {
+        ")" => Err(ParseError::UnmatchedCloseParen { index: 0 }),
+        "(" => parse_parenthetical(rest, options),
+        _ => Ok((Node::Leaf(first.to_string()), rest)),
+    }
+}
 
-    match rest.split_first() {
-        None => left,
-        Some((op, rest)) => match Operator::maybe(op) {
-            None => panic!("operator expected after leaf node"),
-            Some(op) => compose_with_precedence(op, left, parse(rest)),
+fn parse_unary<'a>(
+    op: UnaryOperator,
+    toks: &'a [String],
+    options: &ParseOptions,
+) -> Result<(Node, &'a [String]), ParseError> {
+    let (operand, rest) = parse_unary_operand(toks, options)?;
+    Ok((
+        Node::Unary {
+            op,
+            operand: Box::new(operand),
         },
+        rest,
+    ))
+}
+
+// Parses the operand of a unary operator: a primary expression, optionally
+// followed by `^` and another unary operand (right-associative). Folding in
+// a trailing `^` chain here is what makes `- a ^ b` bind as `-(a ^ b)`
+// rather than `(-a) ^ b` -- see the doc comment on `UnaryOperator`.
+fn parse_unary_operand<'a>(
+    toks: &'a [String],
+    options: &ParseOptions,
+) -> Result<(Node, &'a [String]), ParseError> {
+    let (left, rest) = parse_primary(toks, options)?;
+
+    match rest.split_first() {
+        Some((op, op_rest)) if op == "^" => {
+            if op_rest.is_empty() {
+                return Err(ParseError::TrailingOperator {
+                    index: toks.len() - rest.len(),
+                });
+            }
+            let (right, rest) = parse_unary_operand(op_rest, options)?;
+            Ok((
+                Node::Operation {
+                    op: Operator::Exp,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                },
+                rest,
+            ))
+        }
+        _ => Ok((left, rest)),
     }
 }
 
 // Parses a token stream assuming that the preceding token was an open brace.
 // Returns the first node in the stream (the parsed parenthetical expression), plus
 // any remaining tokens in the stream not part of the parenthetical expression.
-fn parse_parenthetical(toks: &[String]) -> (Node, &[String]) {
+fn parse_parenthetical<'a>(
+    toks: &'a [String],
+    options: &ParseOptions,
+) -> Result<(Node, &'a [String]), ParseError> {
     let mut open = 1;
     let mut close_pos = None;
 
@@ -93,29 +296,109 @@ fn parse_parenthetical(toks: &[String]) -> (Node, &[String]) {
     }
 
     match close_pos {
-        None => panic!("unmatched open brace"),
+        None => Err(ParseError::UnmatchedOpenParen { index: toks.len() }),
         Some(close_pos) => {
             let (inner, after) = toks.split_at(close_pos);
-            (
-                Node::Parenthetical(Box::new(parse(inner))),
+            Ok((
+                Node::Parenthetical(Box::new(parse_with_options(inner, options)?)),
                 after.split_first().unwrap().1, // skip closing brace
-            )
+            ))
+        }
+    }
+}
+
+/// An error produced while reducing a `Node` to a number.
+#[derive(Debug, PartialEq)]
+enum EvalError {
+    InvalidLiteral(String),
+    DivisionByZero,
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidLiteral(s) => write!(f, "invalid numeric literal: {s:?}"),
+            Self::DivisionByZero => write!(f, "division by zero"),
+        }
+    }
+}
+
+impl error::Error for EvalError {}
+
+fn eval(node: &Node) -> Result<f64, EvalError> {
+    match node {
+        Node::Leaf(s) => s
+            .parse::<f64>()
+            .map_err(|_| EvalError::InvalidLiteral(s.clone())),
+        Node::Parenthetical(inner) => eval(inner),
+        Node::Unary { op, operand } => {
+            let operand = eval(operand)?;
+            match op {
+                UnaryOperator::Neg => Ok(-operand),
+                UnaryOperator::Pos => Ok(operand),
+            }
+        }
+        Node::Operation { op, left, right } => {
+            let left = eval(left)?;
+            let right = eval(right)?;
+            match op {
+                Operator::Add => Ok(left + right),
+                Operator::Sub => Ok(left - right),
+                Operator::Mul => Ok(left * right),
+                Operator::Div => {
+                    if right == 0.0 {
+                        Err(EvalError::DivisionByZero)
+                    } else {
+                        Ok(left / right)
+                    }
+                }
+                Operator::Exp => Ok(left.powf(right)),
+                Operator::Eq => Ok((left == right) as u8 as f64),
+                Operator::NotEq => Ok((left != right) as u8 as f64),
+                Operator::Lt => Ok((left < right) as u8 as f64),
+                Operator::Le => Ok((left <= right) as u8 as f64),
+                Operator::Gt => Ok((left > right) as u8 as f64),
+                Operator::Ge => Ok((left >= right) as u8 as f64),
+                // `expr`-style truthiness: `&` yields its first operand if
+                // neither side is zero, else 0; `|` yields the first
+                // non-zero operand, else the second.
+                Operator::And => Ok(if left != 0.0 && right != 0.0 {
+                    left
+                } else {
+                    0.0
+                }),
+                Operator::Or => Ok(if left != 0.0 { left } else { right }),
+            }
         }
     }
 }
 
 fn compose_with_precedence(op: Operator, left: Node, into: Node) -> Node {
+    let descend = match into {
+        Node::Operation { op: ref subnode_op, .. } => match op.associativity() {
+            // Left-associative operators rotate into subnodes of equal or lower
+            // precedence, so `a - b - c` becomes `Sub(Sub(a, b), c)` rather than
+            // `Sub(a, Sub(b, c))`.
+            Associativity::Left => op.cmp_precedence(subnode_op) != Ordering::Less,
+            // Right-associative operators (just `^` for now) only descend into
+            // strictly higher-precedence subnodes, so `a ^ b ^ c` stays
+            // `Exp(a, Exp(b, c))`.
+            Associativity::Right => op.cmp_precedence(subnode_op) == Ordering::Greater,
+        },
+        _ => false,
+    };
+
     match into {
         Node::Operation {
             op: subnode_op,
             left: subnode_left,
             right: subnode_right,
-        } if op.cmp_precedence(&subnode_op) == Ordering::Greater => Node::Operation {
+        } if descend => Node::Operation {
             op: subnode_op,
             left: Box::new(compose_with_precedence(op, left, *subnode_left)),
             right: subnode_right,
         },
-        // operations with equal/lower precedence, leaves, and parentheticals
+        // operations that shouldn't be rotated into, leaves, and parentheticals
         _ => Node::Operation {
             op,
             left: Box::new(left),
@@ -129,9 +412,51 @@ fn main() {
     println!("Hello, world!");
 }
 
-// TODO: allow adjacent tokens that have no whitespace between them, e.g. "(a+b)"
+fn is_operator_char(c: char) -> bool {
+    matches!(
+        c,
+        '+' | '-' | '*' | '/' | '^' | '(' | ')' | '=' | '!' | '<' | '>' | '&' | '|'
+    )
+}
+
+// Scans the input character-by-character so that operators, parens, and
+// leaves can be glued together with no whitespace, e.g. "(a+b)*c".
 fn tokenize(s: &str) -> Vec<String> {
-    s.split_whitespace().map(|s| s.to_string()).collect()
+    let mut toks = Vec::new();
+    let mut chars = s.chars().peekable();
+    let mut leaf = String::new();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            if !leaf.is_empty() {
+                toks.push(std::mem::take(&mut leaf));
+            }
+            chars.next();
+        } else if is_operator_char(c) {
+            if !leaf.is_empty() {
+                toks.push(std::mem::take(&mut leaf));
+            }
+            chars.next();
+
+            // `!=`, `<=`, and `>=` are two-character operators; every other
+            // operator char stands on its own.
+            let mut tok = c.to_string();
+            if matches!(c, '!' | '<' | '>') && chars.peek() == Some(&'=') {
+                tok.push('=');
+                chars.next();
+            }
+            toks.push(tok);
+        } else {
+            leaf.push(c);
+            chars.next();
+        }
+    }
+
+    if !leaf.is_empty() {
+        toks.push(leaf);
+    }
+
+    toks
 }
 
 fn leafbox(s: &str) -> Box<Node> {
@@ -153,13 +478,13 @@ fn test() {
         (
             "a + b - c",
             Node::Operation {
-                op: Operator::Add,
-                left: leafbox("a"),
-                right: Box::new(Node::Operation {
-                    op: Operator::Sub,
-                    left: leafbox("b"),
-                    right: leafbox("c"),
+                op: Operator::Sub,
+                left: Box::new(Node::Operation {
+                    op: Operator::Add,
+                    left: leafbox("a"),
+                    right: leafbox("b"),
                 }),
+                right: leafbox("c"),
             },
         ),
         (
@@ -190,16 +515,16 @@ fn test() {
             "a + b * c + d",
             Node::Operation {
                 op: Operator::Add,
-                left: leafbox("a"),
-                right: Box::new(Node::Operation {
+                left: Box::new(Node::Operation {
                     op: Operator::Add,
-                    left: Box::new(Node::Operation {
+                    left: leafbox("a"),
+                    right: Box::new(Node::Operation {
                         op: Operator::Mul,
                         left: leafbox("b"),
                         right: leafbox("c"),
                     }),
-                    right: leafbox("d"),
                 }),
+                right: leafbox("d"),
             },
         ),
         (
@@ -315,9 +640,275 @@ fn test() {
                 }))),
             },
         ),
+        (
+            "a - b - c",
+            Node::Operation {
+                op: Operator::Sub,
+                left: Box::new(Node::Operation {
+                    op: Operator::Sub,
+                    left: leafbox("a"),
+                    right: leafbox("b"),
+                }),
+                right: leafbox("c"),
+            },
+        ),
+        (
+            "a / b / c",
+            Node::Operation {
+                op: Operator::Div,
+                left: Box::new(Node::Operation {
+                    op: Operator::Div,
+                    left: leafbox("a"),
+                    right: leafbox("b"),
+                }),
+                right: leafbox("c"),
+            },
+        ),
+        (
+            "a ^ b ^ c",
+            Node::Operation {
+                op: Operator::Exp,
+                left: leafbox("a"),
+                right: Box::new(Node::Operation {
+                    op: Operator::Exp,
+                    left: leafbox("b"),
+                    right: leafbox("c"),
+                }),
+            },
+        ),
+    ];
+
+    for (input, want) in cases.iter() {
+        assert_eq!(parse(&tokenize(input)).as_ref(), Ok(want));
+    }
+}
+
+#[test]
+fn test_associativity_eval() {
+    // 10 - 4 - 3 is (10-4)-3 = 3, not 10-(4-3) = 9.
+    let node = parse(&tokenize("10 - 4 - 3")).unwrap();
+    assert_eq!(eval(&node), Ok(3.0));
+
+    // 100 / 10 / 2 is (100/10)/2 = 5, not 100/(10/2) = 20.
+    let node = parse(&tokenize("100 / 10 / 2")).unwrap();
+    assert_eq!(eval(&node), Ok(5.0));
+
+    // 2 ^ 2 ^ 3 is 2^(2^3) = 256, not (2^2)^3 = 64.
+    let node = parse(&tokenize("2 ^ 2 ^ 3")).unwrap();
+    assert_eq!(eval(&node), Ok(256.0));
+}
+
+#[test]
+fn test_parse_errors() {
+    let cases = [
+        ("", ParseError::EmptyInput),
+        ("* a", ParseError::LeadingOperator { index: 0 }),
+        (")", ParseError::UnmatchedCloseParen { index: 0 }),
+        ("( a", ParseError::UnmatchedOpenParen { index: 1 }),
+        ("a b", ParseError::MissingOperator { index: 1 }),
+        ("4 ( 5 )", ParseError::MissingOperator { index: 1 }),
+        ("( a + b ) c", ParseError::MissingOperator { index: 5 }),
+        ("a +", ParseError::TrailingOperator { index: 1 }),
+    ];
+
+    for (input, want) in cases.iter() {
+        assert_eq!(parse(&tokenize(input)).as_ref(), Err(want));
+    }
+}
+
+#[test]
+fn test_implicit_mul() {
+    let options = ParseOptions { implicit_mul: true };
+
+    let cases = [
+        (
+            "2 ( x + 1 )",
+            Node::Operation {
+                op: Operator::Mul,
+                left: leafbox("2"),
+                right: Box::new(Node::Parenthetical(Box::new(Node::Operation {
+                    op: Operator::Add,
+                    left: leafbox("x"),
+                    right: leafbox("1"),
+                }))),
+            },
+        ),
+        (
+            "a b",
+            Node::Operation {
+                op: Operator::Mul,
+                left: leafbox("a"),
+                right: leafbox("b"),
+            },
+        ),
     ];
 
     for (input, want) in cases.iter() {
-        assert_eq!(parse(&tokenize(input)), *want);
+        assert_eq!(
+            parse_with_options(&tokenize(input), &options).as_ref(),
+            Ok(want)
+        );
     }
+
+    assert_eq!(eval(&parse_with_options(&tokenize("2 ( 3 + 1 )"), &options).unwrap()), Ok(8.0));
+
+    // Without the flag, the same input is rejected.
+    assert_eq!(
+        parse(&tokenize("2 ( x + 1 )")),
+        Err(ParseError::MissingOperator { index: 1 })
+    );
+}
+
+#[test]
+fn test_eval() {
+    let cases = [
+        ("1 + 2", 3.0),
+        ("5 - 3", 2.0),
+        ("2 * 3 + 4", 10.0),
+        ("2 + 3 * 4", 14.0),
+        ("2 ^ 3 * 4 + 5", 37.0),
+        ("2 * 3 ^ 4 + 5", 167.0),
+        ("2 * 3 + 4 ^ 5", 1030.0),
+        ("( 1 + 2 ) * 3", 9.0),
+    ];
+
+    for (input, want) in cases.iter() {
+        let node = parse(&tokenize(input)).unwrap();
+        assert_eq!(eval(&node), Ok(*want));
+    }
+}
+
+#[test]
+fn test_eval_errors() {
+    assert_eq!(
+        eval(&parse(&tokenize("1 / 0")).unwrap()),
+        Err(EvalError::DivisionByZero)
+    );
+    assert_eq!(
+        eval(&parse(&tokenize("abc")).unwrap()),
+        Err(EvalError::InvalidLiteral("abc".to_string()))
+    );
+}
+
+#[test]
+fn test_tokenize() {
+    let cases = [
+        ("(a+b)*c", vec!["(", "a", "+", "b", ")", "*", "c"]),
+        ("( a + b ) * c", vec!["(", "a", "+", "b", ")", "*", "c"]),
+        ("(a+b)* c", vec!["(", "a", "+", "b", ")", "*", "c"]),
+        ("foo*2", vec!["foo", "*", "2"]),
+        ("  foo  ", vec!["foo"]),
+        ("", Vec::<&str>::new()),
+    ];
+
+    for (input, want) in cases.iter() {
+        assert_eq!(tokenize(input), *want);
+    }
+}
+
+#[test]
+fn test_unary() {
+    let cases = [
+        (
+            "- a + b",
+            Node::Operation {
+                op: Operator::Add,
+                left: Box::new(Node::Unary {
+                    op: UnaryOperator::Neg,
+                    operand: leafbox("a"),
+                }),
+                right: leafbox("b"),
+            },
+        ),
+        (
+            "- ( a + b )",
+            Node::Unary {
+                op: UnaryOperator::Neg,
+                operand: Box::new(Node::Parenthetical(Box::new(Node::Operation {
+                    op: Operator::Add,
+                    left: leafbox("a"),
+                    right: leafbox("b"),
+                }))),
+            },
+        ),
+        (
+            "a ^ - b",
+            Node::Operation {
+                op: Operator::Exp,
+                left: leafbox("a"),
+                right: Box::new(Node::Unary {
+                    op: UnaryOperator::Neg,
+                    operand: leafbox("b"),
+                }),
+            },
+        ),
+        (
+            "- a ^ b",
+            Node::Unary {
+                op: UnaryOperator::Neg,
+                operand: Box::new(Node::Operation {
+                    op: Operator::Exp,
+                    left: leafbox("a"),
+                    right: leafbox("b"),
+                }),
+            },
+        ),
+    ];
+
+    for (input, want) in cases.iter() {
+        assert_eq!(parse(&tokenize(input)).as_ref(), Ok(want));
+    }
+}
+
+#[test]
+fn test_unary_eval() {
+    assert_eq!(eval(&parse(&tokenize("- 2 + 3")).unwrap()), Ok(1.0));
+    assert_eq!(eval(&parse(&tokenize("- ( 2 + 3 )")).unwrap()), Ok(-5.0));
+    assert_eq!(eval(&parse(&tokenize("2 ^ - 1")).unwrap()), Ok(0.5));
+    assert_eq!(eval(&parse(&tokenize("- 2 ^ 2")).unwrap()), Ok(-4.0));
+    assert_eq!(eval(&parse(&tokenize("3 * - 2")).unwrap()), Ok(-6.0));
+}
+
+#[test]
+fn test_relational_logical_precedence() {
+    // Comparisons bind looser than arithmetic, and `&`/`|` bind looser still,
+    // so `a + b > c & d` is `(a + b > c) & d`.
+    let want = Node::Operation {
+        op: Operator::And,
+        left: Box::new(Node::Operation {
+            op: Operator::Gt,
+            left: Box::new(Node::Operation {
+                op: Operator::Add,
+                left: leafbox("a"),
+                right: leafbox("b"),
+            }),
+            right: leafbox("c"),
+        }),
+        right: leafbox("d"),
+    };
+    assert_eq!(parse(&tokenize("a + b > c & d")).as_ref(), Ok(&want));
+
+    // `&` binds tighter than `|`.
+    let want = Node::Operation {
+        op: Operator::Or,
+        left: leafbox("a"),
+        right: Box::new(Node::Operation {
+            op: Operator::And,
+            left: leafbox("b"),
+            right: leafbox("c"),
+        }),
+    };
+    assert_eq!(parse(&tokenize("a | b & c")).as_ref(), Ok(&want));
+}
+
+#[test]
+fn test_relational_logical_eval() {
+    assert_eq!(eval(&parse(&tokenize("1 + 2 > 2 & 5")).unwrap()), Ok(1.0));
+    assert_eq!(eval(&parse(&tokenize("1 > 2 & 5")).unwrap()), Ok(0.0));
+    assert_eq!(eval(&parse(&tokenize("0 | 3")).unwrap()), Ok(3.0));
+    assert_eq!(eval(&parse(&tokenize("2 | 3")).unwrap()), Ok(2.0));
+    assert_eq!(eval(&parse(&tokenize("3 = 3")).unwrap()), Ok(1.0));
+    assert_eq!(eval(&parse(&tokenize("3 != 3")).unwrap()), Ok(0.0));
+    assert_eq!(eval(&parse(&tokenize("3 <= 4")).unwrap()), Ok(1.0));
+    assert_eq!(eval(&parse(&tokenize("3 >= 4")).unwrap()), Ok(0.0));
 }